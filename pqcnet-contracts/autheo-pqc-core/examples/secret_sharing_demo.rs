@@ -65,6 +65,7 @@ fn run_rotation_demo(
         &rotation.new.id,
         rotation.new.version,
         rotation.new.created_at,
+        rotation.new.epoch,
         policy,
     )?;
     log_package("reshared distribution", &reshared);
@@ -91,6 +92,7 @@ fn share_package(
         &state.id,
         state.version,
         state.created_at,
+        state.epoch,
         policy,
     )
 }
@@ -98,7 +100,7 @@ fn share_package(
 fn verify_quorum(label: &str, expected: &[u8], package: &SecretSharePackage) -> PqcResult<()> {
     let threshold = package.threshold.t as usize;
     let quorum = &package.shares[..threshold];
-    let recovered = combine_secret(quorum)?;
+    let recovered = combine_secret(quorum, &package.commitments, package.threshold.t)?;
     assert_eq!(recovered.secret.as_slice(), expected);
     println!(
         "✔ {label}: reconstructed {} bytes using {} share(s)",
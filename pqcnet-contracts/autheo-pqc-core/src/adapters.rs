@@ -8,6 +8,7 @@ use digest::Digest;
 use spin::Mutex;
 
 const DOMAIN_MLKEM_SK: &[u8] = b"PQCNET_MLKEM_SK_V1";
+const DOMAIN_MLKEM_SK_SEED: &[u8] = b"PQCNET_MLKEM_SK_SEED_V1";
 const DOMAIN_MLKEM_PK: &[u8] = b"PQCNET_MLKEM_PK_V1";
 const DOMAIN_MLKEM_CT: &[u8] = b"PQCNET_MLKEM_CT_V1";
 const DOMAIN_MLKEM_SS: &[u8] = b"PQCNET_MLKEM_SS_V1";
@@ -46,6 +47,12 @@ impl DemoMlKem {
     }
 }
 
+impl Default for DemoMlKem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MlKem for DemoMlKem {
     fn level(&self) -> SecurityLevel {
         SecurityLevel::MlKem128
@@ -63,6 +70,21 @@ impl MlKem for DemoMlKem {
         })
     }
 
+    fn keygen_from_seed(&self, seed: &[u8]) -> PqcResult<MlKemKeyPair> {
+        if seed.is_empty() {
+            return Err(PqcError::InvalidInput("ml-kem seed missing"));
+        }
+
+        let secret_seed = expand_bytes(DOMAIN_MLKEM_SK_SEED, seed, 32);
+        let public_key = expand_bytes(DOMAIN_MLKEM_PK, &secret_seed, 32);
+
+        Ok(MlKemKeyPair {
+            public_key,
+            secret_key: secret_seed,
+            level: self.level(),
+        })
+    }
+
     fn encapsulate(&self, public_key: &[u8]) -> PqcResult<MlKemEncapsulation> {
         if public_key.is_empty() {
             return Err(PqcError::InvalidInput("ml-kem pk missing"));
@@ -111,6 +133,12 @@ impl DemoMlDsa {
     }
 }
 
+impl Default for DemoMlDsa {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MlDsa for DemoMlDsa {
     fn level(&self) -> SecurityLevel {
         SecurityLevel::MlDsa128
@@ -140,7 +168,7 @@ impl MlDsa for DemoMlDsa {
         transcript.extend_from_slice(message);
 
         let mut digest = Blake2s256::new();
-        digest.update(&transcript);
+        digest.update(transcript);
         let sig = digest.finalize();
         Ok(sig.to_vec())
     }
@@ -160,7 +188,7 @@ impl MlDsa for DemoMlDsa {
         transcript.extend_from_slice(message);
 
         let mut digest = Blake2s256::new();
-        digest.update(&transcript);
+        digest.update(transcript);
         let expected = digest.finalize();
 
         if expected.as_slice() == signature {
@@ -171,7 +199,10 @@ impl MlDsa for DemoMlDsa {
     }
 }
 
-fn expand_bytes(domain: &[u8], input: &[u8], len: usize) -> Bytes {
+/// BLAKE2s-based Expand primitive (no Extract step): domain-separated,
+/// counter-mode expansion to an arbitrary output length. Reused by
+/// [`crate::handshake`] as the Expand half of its HKDF-style key schedule.
+pub(crate) fn expand_bytes(domain: &[u8], input: &[u8], len: usize) -> Bytes {
     if len == 0 {
         return Vec::new();
     }
@@ -182,9 +213,9 @@ fn expand_bytes(domain: &[u8], input: &[u8], len: usize) -> Bytes {
     while out.len() < len {
         let mut digest = Blake2s256::new();
         digest.update(domain);
-        digest.update(&(len as u32).to_le_bytes());
+        digest.update((len as u32).to_le_bytes());
         digest.update(input);
-        digest.update(&counter.to_le_bytes());
+        digest.update(counter.to_le_bytes());
 
         let block = digest.finalize();
         let remaining = len - out.len();
@@ -0,0 +1,411 @@
+//! Distributed key generation: every participant shares a random
+//! contribution of its own via [`crate::secret_sharing`], so the resulting
+//! key's secret value is never assembled by any single party. Commitments
+//! are the plain Feldman ones [`crate::secret_sharing`] now uses (see its
+//! module docs for why the Pedersen variant this file used to rely on was
+//! dropped), not hiding, so every aggregate commitment here is subject to
+//! the same documented brute-force leak.
+//!
+//! Each holder sums the sub-shares it receives from all `n` participants to
+//! get a share of the aggregate secret `sum(r_p)`, whose public commitments
+//! are the lane-wise product of every participant's per-coefficient
+//! [`Commitment`]s (not just their constant terms): the aggregate share is a
+//! point on a degree-`t-1` polynomial, so [`verify_share`] needs all `t` of
+//! its commitments per lane, same as any other
+//! [`crate::secret_sharing::SecretSharePackage`]. The aggregate share set
+//! this produces is a normal [`SecretSharePackage`]-style quorum and is
+//! consumed by the existing [`crate::secret_sharing::combine_secret`].
+//!
+//! ## Expected, retryable failure when the aggregate is later combined
+//!
+//! `finalize` itself only assembles the aggregate [`SecretSharePackage`]; it
+//! doesn't reconstruct a secret byte and so never hits the failure below.
+//! That comes later, when a caller combines the aggregate shares with
+//! [`crate::secret_sharing::combine_secret`] (or
+//! [`crate::secret_sharing::combine_secret_robust`]): each lane of the
+//! aggregate secret is `sum(r_p) mod FIELD_PRIME(257)`, and
+//! [`crate::secret_sharing`] rightly refuses to silently wrap the one field
+//! value (`256`) that has no byte representation. For a 32-byte contribution
+//! from just two participants that isn't a corner case: any one of the 32
+//! lanes landing on 256 fails the whole combine, which happens often enough
+//! (around 1 run in 9 for two participants) that a caller must treat that
+//! `Err(PqcError::ReconstructionFailed)` as an expected outcome, not a bug.
+//! There is no way to recover the same run: every participant must restart
+//! from a fresh [`DistributedKeyGeneration::init`] (and so fresh
+//! per-participant randomness) rather than retrying the combine alone.
+//!
+//! ## Turning the aggregate secret into a KEM keypair
+//!
+//! Once a quorum of aggregate shares has been combined into the group's
+//! secret (the `RecoveredSecret` above), [`derive_keypair`] seeds
+//! [`crate::kem::MlKem::keygen_from_seed`] with it instead of letting the
+//! backend mint a secret no participant contributed to. The resulting
+//! `KemKeyState::id` is derived from the keypair's own public key (the same
+//! way [`crate::key_manager::KeyManager::keygen_with_material`] does it), so
+//! every holder who runs [`derive_keypair`] over the same combined secret
+//! ends up agreeing on both the keypair and its id without anyone having
+//! assembled the secret alone.
+
+use crate::error::{PqcError, PqcResult};
+use crate::kem::{MlKemEngine, MlKemKeyPair};
+use crate::key_manager::{derive_key_id, KemKeyState, ThresholdPolicy};
+use crate::secret_sharing::{
+    self, decode_lanes, encode_lanes, field_add, mul_mod, split_secret, verify_share, Commitment,
+    RecoveredSecret, Share, COMMITMENT_MODULUS,
+};
+use crate::types::{KeyId, TimestampMs};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use rand_core::RngCore;
+
+/// Length, in bytes, of the random seed each participant contributes. Matches
+/// the seed size [`crate::adapters::DemoMlKem`] derives its key material from.
+const CONTRIBUTION_LEN: usize = 32;
+
+/// What participant `participant_index` broadcasts to the group: public
+/// commitments to its contribution polynomial, plus one sub-share per holder
+/// (delivered to each holder out of band).
+#[derive(Debug, Clone)]
+pub struct Contribution {
+    pub participant_index: u8,
+    pub commitments: Vec<Commitment>,
+    pub sub_shares: Vec<Share>,
+}
+
+/// One participant's view of a distributed key generation run: it holds a
+/// seat as both a contributor (it shares its own randomness) and a holder
+/// (it accumulates sub-shares from every other participant).
+pub struct DistributedKeyGeneration {
+    policy: ThresholdPolicy,
+    participant_index: u8,
+    lane_count: usize,
+    aggregate_lanes: Vec<u16>,
+    aggregate_commitments: Vec<u64>,
+    disqualified: BTreeSet<u8>,
+    received_from: BTreeSet<u8>,
+}
+
+impl DistributedKeyGeneration {
+    /// Begin a run for the participant at `participant_index` (its own
+    /// position in the `1..=n` holder numbering).
+    pub fn init<R: RngCore>(
+        policy: ThresholdPolicy,
+        participant_index: u8,
+        rng: &mut R,
+    ) -> PqcResult<(Self, [u8; CONTRIBUTION_LEN])> {
+        if policy.t == 0 || policy.n == 0 || policy.t > policy.n {
+            return Err(PqcError::InvalidInput("threshold policy must have 0 < t <= n"));
+        }
+
+        let mut contribution = [0u8; CONTRIBUTION_LEN];
+        rng.fill_bytes(&mut contribution);
+
+        Ok((
+            Self {
+                policy,
+                participant_index,
+                lane_count: CONTRIBUTION_LEN,
+                aggregate_lanes: Vec::new(),
+                aggregate_commitments: Vec::new(),
+                disqualified: BTreeSet::new(),
+                received_from: BTreeSet::new(),
+            },
+            contribution,
+        ))
+    }
+
+    /// Share `own_contribution` under the active policy, returning what this
+    /// participant must broadcast (commitments) and distribute (sub-shares,
+    /// one per holder, delivered out of band).
+    pub fn contribute(
+        &self,
+        own_contribution: &[u8; CONTRIBUTION_LEN],
+        key_id: &KeyId,
+        created_at: TimestampMs,
+    ) -> PqcResult<Contribution> {
+        let package = split_secret(own_contribution, key_id, 1, created_at, 0, self.policy)?;
+        Ok(Contribution {
+            participant_index: self.participant_index,
+            commitments: package.commitments,
+            sub_shares: package.shares,
+        })
+    }
+
+    /// Record the sub-share this holder received from `participant_index`,
+    /// checking it against that participant's broadcast commitments.
+    ///
+    /// A participant whose sub-share doesn't match its own commitments is
+    /// disqualified: its contribution is dropped from the aggregate instead
+    /// of failing the whole run.
+    pub fn receive(
+        &mut self,
+        participant_index: u8,
+        sub_share: &Share,
+        commitments: &[Commitment],
+    ) -> PqcResult<()> {
+        if !self.received_from.insert(participant_index) {
+            return Err(PqcError::InvalidInput("participant already contributed"));
+        }
+
+        if verify_share(sub_share, commitments, self.policy.t).is_err() {
+            self.disqualified.insert(participant_index);
+            return Ok(());
+        }
+
+        let lanes = decode_lanes(&sub_share.value);
+        if lanes.len() != self.lane_count {
+            self.disqualified.insert(participant_index);
+            return Ok(());
+        }
+
+        if self.aggregate_lanes.is_empty() {
+            self.aggregate_lanes = lanes;
+        } else {
+            for (acc, value) in self.aggregate_lanes.iter_mut().zip(lanes.iter()) {
+                *acc = field_add(*acc, *value);
+            }
+        }
+
+        if self.aggregate_commitments.is_empty() {
+            self.aggregate_commitments = commitments.iter().map(|c| c.0).collect();
+        } else {
+            for (acc, value) in self.aggregate_commitments.iter_mut().zip(commitments.iter()) {
+                *acc = mul_mod(*acc, value.0, COMMITMENT_MODULUS);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Participants disqualified so far for sending a sub-share that doesn't
+    /// match their own broadcast commitments.
+    pub fn disqualified(&self) -> &BTreeSet<u8> {
+        &self.disqualified
+    }
+
+    /// Finalize this holder's share of the aggregate secret `sum(r_p)` over
+    /// every participant that wasn't disqualified, plus the flattened
+    /// `lane * t + coefficient_index` public commitments (the lane-wise
+    /// product of every honest participant's per-coefficient [`Commitment`]s)
+    /// any future holder can pass to [`verify_share`] to check a share
+    /// against the aggregate without ever seeing the secret itself.
+    ///
+    /// `Err(PqcError::ReconstructionFailed)` here means no honest
+    /// contribution survived disqualification, not the 256-wrap case
+    /// described in the module docs (that one only shows up later, when the
+    /// aggregate shares this returns are combined).
+    ///
+    /// `Err(PqcError::InvalidInput)` means [`DistributedKeyGeneration::receive`]
+    /// hasn't yet been called for every one of the `n` participants (counting
+    /// disqualifications): a participant that simply never delivered its
+    /// sub-share — dropped on the wire rather than disqualified for a bad
+    /// one — would otherwise let `finalize` silently return a share built
+    /// from whatever partial subset happened to arrive, which disagrees with
+    /// what any other honest holder computes from the full set.
+    pub fn finalize(
+        self,
+        key_id: KeyId,
+        created_at: TimestampMs,
+    ) -> PqcResult<(KemKeyState, Share, Vec<Commitment>)> {
+        if self.received_from.len() != self.policy.n as usize {
+            return Err(PqcError::InvalidInput(
+                "have not received from every participant yet",
+            ));
+        }
+        if self.aggregate_lanes.len() != self.lane_count {
+            return Err(PqcError::ReconstructionFailed(
+                "no honest contributions were received",
+            ));
+        }
+
+        let state = KemKeyState {
+            id: key_id,
+            version: 1,
+            created_at,
+            epoch: 0,
+        };
+
+        let share = Share {
+            metadata: secret_sharing::ShareMetadata {
+                share_index: self.participant_index,
+                key_id,
+                key_version: 1,
+                created_at,
+                epoch: 0,
+            },
+            value: encode_lanes(&self.aggregate_lanes),
+        };
+
+        let aggregate_commitments = self
+            .aggregate_commitments
+            .into_iter()
+            .map(Commitment)
+            .collect();
+
+        Ok((state, share, aggregate_commitments))
+    }
+}
+
+/// Seed `engine` with a DKG run's combined secret (the output of
+/// [`crate::secret_sharing::combine_secret`] or
+/// [`crate::secret_sharing::combine_secret_robust`] over the aggregate shares
+/// [`DistributedKeyGeneration::finalize`] produced) to derive the actual KEM
+/// keypair nobody assembled alone.
+///
+/// `key_id` is derived from the resulting public key, the same way
+/// [`crate::key_manager::KeyManager::keygen_with_material`] derives it, so
+/// every holder running this over the same combined secret agrees on the
+/// same id without comparing notes.
+pub fn derive_keypair(
+    engine: &MlKemEngine,
+    recovered: &RecoveredSecret,
+    created_at: TimestampMs,
+) -> PqcResult<(KemKeyState, MlKemKeyPair)> {
+    let material = engine.keygen_from_seed(&recovered.secret)?;
+    let state = KemKeyState {
+        id: derive_key_id(&material.public_key, 1),
+        version: 1,
+        created_at,
+        epoch: 0,
+    };
+    Ok((state, material))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::DemoMlKem;
+    use crate::secret_sharing::combine_secret;
+    use crate::types::KeyId;
+    use alloc::boxed::Box;
+
+    /// Deterministic `RngCore` so DKG tests don't depend on real randomness;
+    /// the contributions used in these tests are overridden with fixed bytes
+    /// anyway, so this only needs to satisfy `DistributedKeyGeneration::init`.
+    struct StepRng(u64);
+
+    impl RngCore for StepRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn two_honest_participants_produce_a_recoverable_aggregate() {
+        let policy = ThresholdPolicy { t: 2, n: 2 };
+        let key_id = KeyId([9u8; 32]);
+        let mut rng = StepRng(1);
+
+        let (mut dkg1, _) = DistributedKeyGeneration::init(policy, 1, &mut rng).unwrap();
+        let (mut dkg2, _) = DistributedKeyGeneration::init(policy, 2, &mut rng).unwrap();
+
+        let contribution1 = [2u8; CONTRIBUTION_LEN];
+        let contribution2 = [3u8; CONTRIBUTION_LEN];
+
+        let contrib1 = dkg1.contribute(&contribution1, &key_id, 1_000).unwrap();
+        let contrib2 = dkg2.contribute(&contribution2, &key_id, 1_000).unwrap();
+
+        dkg1.receive(1, &contrib1.sub_shares[0], &contrib1.commitments).unwrap();
+        dkg1.receive(2, &contrib2.sub_shares[0], &contrib2.commitments).unwrap();
+        dkg2.receive(1, &contrib1.sub_shares[1], &contrib1.commitments).unwrap();
+        dkg2.receive(2, &contrib2.sub_shares[1], &contrib2.commitments).unwrap();
+
+        assert!(dkg1.disqualified().is_empty());
+        assert!(dkg2.disqualified().is_empty());
+
+        let (_, share1, commitments1) = dkg1.finalize(key_id, 1_000).unwrap();
+        let (_, share2, commitments2) = dkg2.finalize(key_id, 1_000).unwrap();
+        assert_eq!(
+            commitments1.iter().map(|c| c.0).collect::<Vec<_>>(),
+            commitments2.iter().map(|c| c.0).collect::<Vec<_>>()
+        );
+
+        let recovered = combine_secret(&[share1, share2], &commitments1, 2).unwrap();
+        let expected: Vec<u8> = contribution1
+            .iter()
+            .zip(contribution2.iter())
+            .map(|(&a, &b)| a + b)
+            .collect();
+        assert_eq!(recovered.secret, expected);
+
+        let engine = MlKemEngine::new(Box::new(DemoMlKem::new()));
+        let (state, keypair) = derive_keypair(&engine, &recovered, 1_000).unwrap();
+        assert_eq!(state.id, derive_key_id(&keypair.public_key, 1));
+
+        // Every holder runs this over the same combined secret, so it must
+        // be deterministic: nobody assembled the secret alone, but everyone
+        // who does this step lands on the same keypair.
+        let (other_state, other_keypair) = derive_keypair(&engine, &recovered, 1_000).unwrap();
+        assert_eq!(state.id, other_state.id);
+        assert_eq!(keypair.public_key, other_keypair.public_key);
+        assert_eq!(keypair.secret_key, other_keypair.secret_key);
+    }
+
+    #[test]
+    fn finalize_rejects_a_run_missing_a_participant_that_never_arrived() {
+        // n=3, but only 1 of the other 2 participants' sub-shares ever show
+        // up — dropped on the wire, not disqualified for a bad sub-share.
+        let policy = ThresholdPolicy { t: 2, n: 3 };
+        let key_id = KeyId([11u8; 32]);
+        let mut rng = StepRng(1);
+
+        let (mut dkg1, _) = DistributedKeyGeneration::init(policy, 1, &mut rng).unwrap();
+
+        let contribution1 = [6u8; CONTRIBUTION_LEN];
+        let contrib1 = dkg1.contribute(&contribution1, &key_id, 1_000).unwrap();
+
+        dkg1.receive(1, &contrib1.sub_shares[0], &contrib1.commitments).unwrap();
+        // Participants 2 and 3 never deliver anything; only 1 of 3 heard from.
+
+        assert!(dkg1.disqualified().is_empty());
+        assert!(matches!(
+            dkg1.finalize(key_id, 1_000),
+            Err(PqcError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn a_tampered_sub_share_is_disqualified_not_fatal() {
+        // t=1 so an honest holder's own share value is the contribution's
+        // constant term directly, with no other coefficient mixed in.
+        let policy = ThresholdPolicy { t: 1, n: 2 };
+        let key_id = KeyId([10u8; 32]);
+        let mut rng = StepRng(1);
+
+        let (mut dkg1, _) = DistributedKeyGeneration::init(policy, 1, &mut rng).unwrap();
+        let (dkg2, _) = DistributedKeyGeneration::init(policy, 2, &mut rng).unwrap();
+
+        let contribution1 = [4u8; CONTRIBUTION_LEN];
+        let contribution2 = [5u8; CONTRIBUTION_LEN];
+
+        let contrib1 = dkg1.contribute(&contribution1, &key_id, 1_000).unwrap();
+        let mut contrib2 = dkg2.contribute(&contribution2, &key_id, 1_000).unwrap();
+        contrib2.sub_shares[0].value[0] ^= 0xFF;
+
+        dkg1.receive(1, &contrib1.sub_shares[0], &contrib1.commitments).unwrap();
+        dkg1.receive(2, &contrib2.sub_shares[0], &contrib2.commitments).unwrap();
+
+        assert!(dkg1.disqualified().contains(&2));
+
+        let (_, share, _) = dkg1.finalize(key_id, 1_000).unwrap();
+        let expected: Vec<u16> = contribution1.iter().map(|&b| b as u16).collect();
+        assert_eq!(decode_lanes(&share.value), expected);
+    }
+}
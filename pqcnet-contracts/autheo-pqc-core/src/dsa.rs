@@ -0,0 +1,20 @@
+//! Digital-signature engine trait implemented by ML-DSA backends.
+
+use crate::error::PqcResult;
+use crate::types::{Bytes, SecurityLevel};
+
+/// A key pair produced by an [`MlDsa`] engine.
+#[derive(Debug, Clone)]
+pub struct MlDsaKeyPair {
+    pub public_key: Bytes,
+    pub secret_key: Bytes,
+    pub level: SecurityLevel,
+}
+
+/// Trait implemented by every ML-DSA backend (demo or audited).
+pub trait MlDsa {
+    fn level(&self) -> SecurityLevel;
+    fn keygen(&self) -> PqcResult<MlDsaKeyPair>;
+    fn sign(&self, secret_key: &[u8], message: &[u8]) -> PqcResult<Bytes>;
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> PqcResult<()>;
+}
@@ -0,0 +1,17 @@
+//! Error type shared by every fallible operation in the crate.
+
+/// Result alias used throughout the crate's public API.
+pub type PqcResult<T> = Result<T, PqcError>;
+
+/// Failure modes surfaced by PQC engines, secret sharing and key management.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PqcError {
+    /// A caller-supplied argument was malformed or missing.
+    InvalidInput(&'static str),
+    /// A signature, MAC or share failed to verify.
+    VerifyFailed,
+    /// A threshold-sharing operation received an inconsistent set of shares.
+    ShareMismatch(&'static str),
+    /// Reconstruction could not recover a consistent secret.
+    ReconstructionFailed(&'static str),
+}
@@ -0,0 +1,69 @@
+//! Key-encapsulation engine trait and the stateful wrapper used by
+//! [`crate::key_manager::KeyManager`].
+
+use crate::error::PqcResult;
+use crate::types::{Bytes, SecurityLevel};
+use alloc::boxed::Box;
+
+/// A key pair produced by an [`MlKem`] engine.
+#[derive(Debug, Clone)]
+pub struct MlKemKeyPair {
+    pub public_key: Bytes,
+    pub secret_key: Bytes,
+    pub level: SecurityLevel,
+}
+
+/// The output of encapsulating against a peer's public key: the ciphertext
+/// to send them and the shared secret only the holder of the matching
+/// secret key can also derive.
+#[derive(Debug, Clone)]
+pub struct MlKemEncapsulation {
+    pub ciphertext: Bytes,
+    pub shared_secret: Bytes,
+}
+
+/// Trait implemented by every ML-KEM backend (demo or audited).
+pub trait MlKem {
+    fn level(&self) -> SecurityLevel;
+    fn keygen(&self) -> PqcResult<MlKemKeyPair>;
+    /// Derive a keypair deterministically from `seed` instead of the
+    /// backend's own randomness. Used by [`crate::dkg`] to turn a
+    /// reconstructed distributed-key-generation secret into the group's
+    /// actual KEM keypair, since no single participant ever holds that
+    /// secret to run the ordinary [`MlKem::keygen`] themselves.
+    fn keygen_from_seed(&self, seed: &[u8]) -> PqcResult<MlKemKeyPair>;
+    fn encapsulate(&self, public_key: &[u8]) -> PqcResult<MlKemEncapsulation>;
+    fn decapsulate(&self, secret_key: &[u8], ciphertext: &[u8]) -> PqcResult<Bytes>;
+}
+
+/// Owns a boxed [`MlKem`] backend so callers can swap the demo adapter for
+/// the audited engine without touching the rest of the stack.
+pub struct MlKemEngine {
+    backend: Box<dyn MlKem>,
+}
+
+impl MlKemEngine {
+    pub fn new(backend: Box<dyn MlKem>) -> Self {
+        Self { backend }
+    }
+
+    pub fn level(&self) -> SecurityLevel {
+        self.backend.level()
+    }
+
+    pub fn keygen(&self) -> PqcResult<MlKemKeyPair> {
+        self.backend.keygen()
+    }
+
+    pub fn keygen_from_seed(&self, seed: &[u8]) -> PqcResult<MlKemKeyPair> {
+        self.backend.keygen_from_seed(seed)
+    }
+
+    pub fn encapsulate(&self, public_key: &[u8]) -> PqcResult<MlKemEncapsulation> {
+        self.backend.encapsulate(public_key)
+    }
+
+    pub fn decapsulate(&self, secret_key: &[u8], ciphertext: &[u8]) -> PqcResult<Bytes> {
+        self.backend.decapsulate(secret_key, ciphertext)
+    }
+}
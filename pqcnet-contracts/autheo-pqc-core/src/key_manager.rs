@@ -0,0 +1,337 @@
+//! Lifecycle management for a threshold-shared KEM key: bootstrap, time-based
+//! rotation, and (re)sharing of the resulting secret material.
+
+use crate::error::{PqcError, PqcResult};
+use crate::kem::{MlKemEngine, MlKemKeyPair};
+use crate::secret_sharing::{
+    combine_secret, combine_secret_robust, reshare_zero, split_secret, RecoveredSecret,
+    SecretSharePackage,
+};
+use crate::types::{KeyId, TimestampMs};
+
+/// `t`-of-`n` threshold under which a key's shares are distributed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdPolicy {
+    pub t: u8,
+    pub n: u8,
+}
+
+/// Public bookkeeping for one version of a managed key.
+#[derive(Debug, Clone, Copy)]
+pub struct KemKeyState {
+    pub id: KeyId,
+    pub version: u32,
+    pub created_at: TimestampMs,
+    /// Bumped by [`KeyManager::refresh_shares`] each time the outstanding
+    /// shares are proactively re-randomized without minting a new key.
+    pub epoch: u32,
+}
+
+/// Before/after snapshot returned when [`KeyManager::rotate_with_material`]
+/// mints a new key.
+#[derive(Debug, Clone)]
+pub struct KemRotation {
+    pub old: KemKeyState,
+    pub new: KemKeyState,
+    pub new_material: MlKemKeyPair,
+}
+
+/// Drives the lifecycle of a single threshold-shared KEM key: bootstrap,
+/// time-based rotation, and resharing.
+pub struct KeyManager {
+    engine: MlKemEngine,
+    policy: ThresholdPolicy,
+    rotation_interval_ms: u64,
+    current: Option<KemKeyState>,
+    current_shares: Option<SecretSharePackage>,
+}
+
+impl KeyManager {
+    pub fn new(engine: MlKemEngine, policy: ThresholdPolicy, rotation_interval_ms: u64) -> Self {
+        Self {
+            engine,
+            policy,
+            rotation_interval_ms,
+            current: None,
+            current_shares: None,
+        }
+    }
+
+    pub fn policy(&self) -> ThresholdPolicy {
+        self.policy
+    }
+
+    pub fn current(&self) -> Option<KemKeyState> {
+        self.current
+    }
+
+    /// Record the distribution the caller handed out for the current key, so
+    /// a later [`KeyManager::refresh_shares`] has something to re-randomize.
+    pub fn track_shares(&mut self, package: SecretSharePackage) {
+        self.current_shares = Some(package);
+    }
+
+    /// Generate the first version of the managed key.
+    pub fn keygen_with_material(&mut self, now_ms: TimestampMs) -> PqcResult<(KemKeyState, MlKemKeyPair)> {
+        let material = self.engine.keygen()?;
+        let state = KemKeyState {
+            id: derive_key_id(&material.public_key, 1),
+            version: 1,
+            created_at: now_ms,
+            epoch: 0,
+        };
+        self.current = Some(state);
+        self.current_shares = None;
+        Ok((state, material))
+    }
+
+    /// Mint a new key version if `rotation_interval_ms` has elapsed since the
+    /// current key was created. Returns `None` when rotation isn't due yet.
+    pub fn rotate_with_material(&mut self, now_ms: TimestampMs) -> PqcResult<Option<KemRotation>> {
+        let old = match self.current {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+
+        if now_ms < old.created_at.saturating_add(self.rotation_interval_ms) {
+            return Ok(None);
+        }
+
+        let material = self.engine.keygen()?;
+        let new = KemKeyState {
+            id: derive_key_id(&material.public_key, old.version + 1),
+            version: old.version + 1,
+            created_at: now_ms,
+            epoch: 0,
+        };
+        self.current = Some(new);
+        self.current_shares = None;
+
+        Ok(Some(KemRotation {
+            old,
+            new,
+            new_material: material,
+        }))
+    }
+
+    /// Proactively re-randomize every outstanding share of the current key
+    /// without changing the secret, `key_id`, or `version`: defeats an
+    /// attacker who has been slowly harvesting shares below the threshold
+    /// across epochs, since shares from different epochs can never be
+    /// combined (see [`crate::secret_sharing::combine_secret`]).
+    ///
+    /// Requires a prior [`KeyManager::track_shares`] call so the manager has
+    /// the outstanding distribution to re-randomize.
+    pub fn refresh_shares(&mut self, now_ms: TimestampMs) -> PqcResult<SecretSharePackage> {
+        self.refresh_shares_with_policy(now_ms, None)
+    }
+
+    /// Like [`KeyManager::refresh_shares`], but also changing the threshold
+    /// policy (and so the holder set) in the same step. Because the holder
+    /// set is changing, this reconstructs the secret from the outgoing
+    /// quorum and re-shares it onto a freshly sized polynomial rather than
+    /// zero-sharing onto the old one.
+    pub fn refresh_shares_with_policy(
+        &mut self,
+        now_ms: TimestampMs,
+        new_policy: Option<ThresholdPolicy>,
+    ) -> PqcResult<SecretSharePackage> {
+        let state = self
+            .current
+            .ok_or(PqcError::InvalidInput("no active key to refresh"))?;
+        let package = self
+            .current_shares
+            .as_ref()
+            .ok_or(PqcError::InvalidInput("no outstanding shares to refresh"))?;
+
+        let refreshed = match new_policy {
+            None => reshare_zero(package, now_ms)?,
+            Some(policy) => {
+                let threshold = package.threshold.t as usize;
+                if package.shares.len() < threshold {
+                    return Err(PqcError::InvalidInput(
+                        "not enough outstanding shares left to meet the threshold",
+                    ));
+                }
+                let recovered = combine_secret(
+                    &package.shares[..threshold],
+                    &package.commitments,
+                    package.threshold.t,
+                )?;
+                split_secret(
+                    &recovered.secret,
+                    &state.id,
+                    state.version,
+                    now_ms,
+                    package.epoch + 1,
+                    policy,
+                )?
+            }
+        };
+
+        // Only commit to discarding the outgoing shares once every fallible
+        // step above has already succeeded: a rejected `new_policy` (or a
+        // share that fails verification) must leave `current_shares` intact
+        // for the caller to retry, not drop it for good.
+        self.policy = refreshed.threshold;
+        self.current = Some(KemKeyState {
+            epoch: refreshed.epoch,
+            ..state
+        });
+        self.current_shares = Some(refreshed.clone());
+
+        Ok(refreshed)
+    }
+
+    /// Reconstruct the current key's secret tolerating up to the largest
+    /// correctable number of corrupted outstanding shares (see
+    /// [`crate::secret_sharing::combine_secret_robust`]), evicting any share
+    /// index the decode flags as corrupt from the tracked distribution so a
+    /// later [`KeyManager::refresh_shares`] stops handing that holder a fresh
+    /// share.
+    pub fn reconstruct_robust(&mut self) -> PqcResult<RecoveredSecret> {
+        let package = self
+            .current_shares
+            .as_ref()
+            .ok_or(PqcError::InvalidInput("no outstanding shares to reconstruct from"))?;
+
+        let recovery = combine_secret_robust(&package.shares, package.threshold.t)?;
+        if !recovery.corrupted_shares.is_empty() {
+            let corrupted = recovery.corrupted_shares;
+            self.current_shares
+                .as_mut()
+                .expect("just checked current_shares is Some above")
+                .shares
+                .retain(|share| !corrupted.contains(&share.metadata.share_index));
+        }
+
+        Ok(RecoveredSecret { secret: recovery.secret })
+    }
+}
+
+pub(crate) fn derive_key_id(public_key: &[u8], version: u32) -> KeyId {
+    use blake2::Blake2s256;
+    use digest::Digest;
+
+    const DOMAIN_KEY_ID: &[u8] = b"PQCNET_KEY_ID_V1";
+
+    let mut digest = Blake2s256::new();
+    digest.update(DOMAIN_KEY_ID);
+    digest.update(version.to_le_bytes());
+    digest.update(public_key);
+    let out = digest.finalize();
+
+    let mut id = [0u8; 32];
+    id.copy_from_slice(&out);
+    KeyId(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::DemoMlKem;
+    use crate::secret_sharing::{combine_secret, split_secret};
+    use alloc::boxed::Box;
+
+    fn build_manager(policy: ThresholdPolicy) -> KeyManager {
+        let engine = MlKemEngine::new(Box::new(DemoMlKem::new()));
+        KeyManager::new(engine, policy, 60_000)
+    }
+
+    #[test]
+    fn refresh_shares_bumps_the_epoch_without_changing_the_secret() {
+        let policy = ThresholdPolicy { t: 2, n: 3 };
+        let mut manager = build_manager(policy);
+        let (state, pair) = manager.keygen_with_material(1_000).unwrap();
+        let package = split_secret(&pair.secret_key, &state.id, state.version, state.created_at, state.epoch, policy).unwrap();
+        manager.track_shares(package);
+
+        let refreshed = manager.refresh_shares(2_000).unwrap();
+        assert_eq!(refreshed.epoch, 1);
+        assert_eq!(manager.current().unwrap().epoch, 1);
+
+        let recovered = combine_secret(&refreshed.shares[..2], &refreshed.commitments, 2).unwrap();
+        assert_eq!(recovered.secret, pair.secret_key);
+    }
+
+    #[test]
+    fn a_rejected_policy_change_does_not_lose_the_outstanding_shares() {
+        let policy = ThresholdPolicy { t: 2, n: 3 };
+        let mut manager = build_manager(policy);
+        let (state, pair) = manager.keygen_with_material(1_000).unwrap();
+        let package = split_secret(&pair.secret_key, &state.id, state.version, state.created_at, state.epoch, policy).unwrap();
+        manager.track_shares(package);
+
+        let bad_policy = ThresholdPolicy { t: 0, n: 3 };
+        assert!(manager.refresh_shares_with_policy(2_000, Some(bad_policy)).is_err());
+
+        // The outgoing shares must still be there for a subsequent refresh.
+        let refreshed = manager.refresh_shares(3_000).unwrap();
+        assert_eq!(refreshed.epoch, 1);
+    }
+
+    #[test]
+    fn refresh_shares_with_policy_rejects_a_package_pruned_below_threshold() {
+        let policy = ThresholdPolicy { t: 3, n: 5 };
+        let mut manager = build_manager(policy);
+        let (state, pair) = manager.keygen_with_material(1_000).unwrap();
+        let mut package = split_secret(&pair.secret_key, &state.id, state.version, state.created_at, state.epoch, policy).unwrap();
+        package.shares.retain(|share| share.metadata.share_index <= 2);
+        manager.track_shares(package);
+
+        let new_policy = ThresholdPolicy { t: 2, n: 4 };
+        assert!(matches!(
+            manager.refresh_shares_with_policy(2_000, Some(new_policy)),
+            Err(PqcError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn reconstruct_robust_evicts_the_corrupted_share_it_flags() {
+        // t + 2e <= n, so with t=2 a single corrupted share (e=1) needs n>=4.
+        let policy = ThresholdPolicy { t: 2, n: 4 };
+        let mut manager = build_manager(policy);
+        let (state, pair) = manager.keygen_with_material(1_000).unwrap();
+        let mut package = split_secret(&pair.secret_key, &state.id, state.version, state.created_at, state.epoch, policy).unwrap();
+        package.shares[3].value[0] ^= 0x01;
+        manager.track_shares(package);
+
+        let recovered = manager.reconstruct_robust().unwrap();
+        assert_eq!(recovered.secret, pair.secret_key);
+
+        let remaining: Vec<u8> = manager
+            .current_shares
+            .as_ref()
+            .unwrap()
+            .shares
+            .iter()
+            .map(|share| share.metadata.share_index)
+            .collect();
+        assert_eq!(remaining, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reconstruct_robust_catches_corruption_with_holders_beyond_the_minimal_quorum() {
+        // n (6) exceeds the minimal t + 2e (2 + 2*1 = 4) needed for e=1, so a
+        // real deployment with extra redundancy must still be covered.
+        let policy = ThresholdPolicy { t: 2, n: 6 };
+        let mut manager = build_manager(policy);
+        let (state, pair) = manager.keygen_with_material(1_000).unwrap();
+        let mut package = split_secret(&pair.secret_key, &state.id, state.version, state.created_at, state.epoch, policy).unwrap();
+        package.shares[5].value[0] ^= 0x01;
+        manager.track_shares(package);
+
+        let recovered = manager.reconstruct_robust().unwrap();
+        assert_eq!(recovered.secret, pair.secret_key);
+
+        let remaining: Vec<u8> = manager
+            .current_shares
+            .as_ref()
+            .unwrap()
+            .shares
+            .iter()
+            .map(|share| share.metadata.share_index)
+            .collect();
+        assert_eq!(remaining, vec![1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,20 @@
+//! Core PQC primitives and contract-facing adapters for PQCnet.
+//!
+//! This crate is `no_std` so it can be linked into WASM contract runtimes;
+//! it relies on `alloc` for heap-allocated buffers. Test builds opt back
+//! into `std` so `#[cfg(test)]` modules can use the host allocator and
+//! assertion machinery directly.
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+pub mod adapters;
+pub mod dkg;
+pub mod dsa;
+pub mod error;
+pub mod handshake;
+pub mod kem;
+pub mod key_manager;
+pub mod secret_sharing;
+pub mod types;
@@ -0,0 +1,843 @@
+//! Threshold (`t`-of-`n`) secret sharing for KEM secret material.
+//!
+//! Shares are computed lane-by-lane: each byte of the secret is the
+//! constant term of its own degree-`(t-1)` polynomial over the prime
+//! field `GF(257)` (the smallest prime above `u8::MAX`, so every byte
+//! value is a valid field element). Coefficients are derived
+//! deterministically from the secret and key metadata via domain-separated
+//! BLAKE2s expansion, following the same pattern as [`crate::adapters`].
+//!
+//! Holders additionally receive [`Commitment`]s to each lane's polynomial
+//! coefficients, so a share can be checked against the dealer's commitments
+//! without trusting the dealer or any other holder. These are plain Feldman
+//! commitments `C_j = g^{a_j} mod q`, not the Pedersen-style `g^{a_j} h^{b_j}`
+//! construction an earlier version of this file used: that variant needed
+//! both generators to have order exactly `FIELD_PRIME` (so an exponent could
+//! be reduced mod 257 the same way the committed coefficient is), which puts
+//! `log_g(h)` in a search space of only 257 values. Brute-forcing that
+//! relation lets anyone forge a blinding value that makes an arbitrary fake
+//! share pass [`verify_share`] against the real commitments, which defeats
+//! the whole point of checking a share against them. Plain Feldman
+//! commitments are binding without that relation, at the cost of being
+//! undone by the same brute force: a coefficient is a field element with
+//! only `FIELD_PRIME` possible values, so anyone who can see the published
+//! commitments can recover the committed byte by trying all 257 candidates.
+//! For this demo engine (parameters chosen for clarity, not audited for
+//! production use — see [`crate::adapters`]) that residual leak is accepted
+//! and documented rather than solved; a real deployment would need a
+//! commitment group whose order isn't pinned to the sharing field at all.
+
+use crate::error::{PqcError, PqcResult};
+use crate::key_manager::ThresholdPolicy;
+use crate::types::{Bytes, KeyId, TimestampMs};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use blake2::Blake2s256;
+use digest::Digest;
+
+/// Prime modulus for the per-byte sharing field; every `u8` is a valid
+/// element since `257 > u8::MAX`.
+const FIELD_PRIME: u32 = 257;
+
+/// Modulus of the cyclic group used for Feldman commitments: a 61-bit prime
+/// `Q` chosen so `FIELD_PRIME` divides `Q - 1`. That's what lets
+/// [`verify_share`] compare `g^{a mod FIELD_PRIME}` against the real
+/// (unreduced) `g^a`: [`COMMITMENT_GENERATOR`] has order exactly
+/// `FIELD_PRIME` in this group, so `g^FIELD_PRIME == 1 (mod Q)` and raising
+/// `g` to any two integers congruent mod `FIELD_PRIME` gives the same
+/// result. Picking `Q` to just be a large prime (as an earlier version of
+/// this file did) doesn't have that property and makes every honest share
+/// fail verification as soon as a coefficient/`x` power wraps past
+/// `FIELD_PRIME`.
+pub(crate) const COMMITMENT_MODULUS: u64 = 2_305_843_009_213_691_929;
+
+/// Generator used for Feldman commitments, of order exactly `FIELD_PRIME` in
+/// the group of order `COMMITMENT_MODULUS - 1` (see [`COMMITMENT_MODULUS`]).
+/// Picked for this demo engine the same way [`crate::adapters`] picks its
+/// domain tags: fixed and documented, not derived from an audited parameter
+/// set or a "nothing-up-my-sleeve" construction.
+const COMMITMENT_GENERATOR: u64 = 2_063_393_432_743_977_859;
+
+const DOMAIN_SHARE_COEFF: &[u8] = b"PQCNET_SHARE_COEFF_V1";
+
+/// Per-lane polynomial coefficient commitment, `C = g^a mod q`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Commitment(pub u64);
+
+#[derive(Debug, Clone)]
+pub struct ShareMetadata {
+    pub share_index: u8,
+    pub key_id: KeyId,
+    pub key_version: u32,
+    pub created_at: TimestampMs,
+    /// Which proactive refresh (see [`crate::key_manager::KeyManager::refresh_shares`])
+    /// this share belongs to. Shares from different epochs must never be
+    /// interpolated together.
+    pub epoch: u32,
+}
+
+/// One holder's share of every byte lane of the secret.
+#[derive(Debug, Clone)]
+pub struct Share {
+    pub metadata: ShareMetadata,
+    /// Little-endian `u16` per lane, one lane per secret byte.
+    pub value: Bytes,
+}
+
+/// A `t`-of-`n` distribution of a secret: the shares to hand out plus the
+/// public commitments holders use to verify them.
+#[derive(Debug, Clone)]
+pub struct SecretSharePackage {
+    pub key_id: KeyId,
+    pub key_version: u32,
+    pub created_at: TimestampMs,
+    pub epoch: u32,
+    pub threshold: ThresholdPolicy,
+    pub shares: Vec<Share>,
+    /// Flattened `lane * threshold.t + coefficient_index` commitments.
+    pub commitments: Vec<Commitment>,
+}
+
+/// The result of a successful [`combine_secret`] or [`combine_secret_robust`].
+#[derive(Debug, Clone)]
+pub struct RecoveredSecret {
+    pub secret: Bytes,
+}
+
+/// Split `secret` into an `n`-holder, `t`-threshold [`SecretSharePackage`] for
+/// the given `epoch` (`0` for a freshly generated key; see
+/// [`crate::key_manager::KeyManager::refresh_shares`] for later epochs).
+pub fn split_secret(
+    secret: &[u8],
+    key_id: &KeyId,
+    key_version: u32,
+    created_at: TimestampMs,
+    epoch: u32,
+    policy: ThresholdPolicy,
+) -> PqcResult<SecretSharePackage> {
+    if policy.t == 0 || policy.n == 0 || policy.t > policy.n {
+        return Err(PqcError::InvalidInput("threshold policy must have 0 < t <= n"));
+    }
+    if secret.is_empty() {
+        return Err(PqcError::InvalidInput("secret must not be empty"));
+    }
+
+    let t = policy.t as usize;
+    let n = policy.n as usize;
+
+    // coefficients[lane][j], j in 0..t, a_0 is the secret byte for that lane.
+    let coefficients: Vec<Vec<u16>> = secret
+        .iter()
+        .enumerate()
+        .map(|(lane, &byte)| {
+            let mut coeffs = vec![byte as u16];
+            for j in 1..t {
+                coeffs.push(derive_coefficient(
+                    DOMAIN_SHARE_COEFF,
+                    secret,
+                    key_id,
+                    key_version,
+                    created_at,
+                    epoch,
+                    lane,
+                    j,
+                ));
+            }
+            coeffs
+        })
+        .collect();
+
+    let commitments = coefficients
+        .iter()
+        .flat_map(|lane_coeffs| lane_coeffs.iter().map(|&a| commit(a)))
+        .collect();
+
+    let shares = (1..=n)
+        .map(|index| {
+            let x = index as u16;
+            let value: Vec<u16> = coefficients
+                .iter()
+                .map(|lane_coeffs| evaluate_polynomial(lane_coeffs, x))
+                .collect();
+            Share {
+                metadata: ShareMetadata {
+                    share_index: index as u8,
+                    key_id: *key_id,
+                    key_version,
+                    created_at,
+                    epoch,
+                },
+                value: encode_lanes(&value),
+            }
+        })
+        .collect();
+
+    Ok(SecretSharePackage {
+        key_id: *key_id,
+        key_version,
+        created_at,
+        epoch,
+        threshold: policy,
+        shares,
+        commitments,
+    })
+}
+
+/// Verify that `share` lies on the polynomial committed to by `commitments`,
+/// as published in a [`SecretSharePackage`].
+///
+/// `threshold` must match the `t` used when the commitments were produced;
+/// it is how the flattened `commitments` slice is split back into lanes.
+pub fn verify_share(share: &Share, commitments: &[Commitment], threshold: u8) -> PqcResult<()> {
+    let t = threshold as usize;
+    if t == 0 || !commitments.len().is_multiple_of(t) {
+        return Err(PqcError::InvalidInput("commitments length is not a multiple of t"));
+    }
+
+    let lanes = decode_lanes(&share.value);
+    if lanes.len() * t != commitments.len() {
+        return Err(PqcError::ShareMismatch("share lane count does not match commitments"));
+    }
+
+    let x = share.metadata.share_index as u16;
+    for (lane, &s_i) in lanes.iter().enumerate() {
+        let lane_commitments = &commitments[lane * t..(lane + 1) * t];
+
+        let lhs = mod_pow(COMMITMENT_GENERATOR, s_i as u64, COMMITMENT_MODULUS);
+        let mut rhs = 1u64;
+        let mut x_pow = 1u16; // x^0
+        for commitment in lane_commitments {
+            rhs = mul_mod(rhs, mod_pow(commitment.0, x_pow as u64, COMMITMENT_MODULUS), COMMITMENT_MODULUS);
+            x_pow = field_mul(x_pow, x);
+        }
+
+        if lhs != rhs {
+            return Err(PqcError::VerifyFailed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reconstruct the secret by Lagrange-interpolating `t` shares, rejecting any
+/// that fail [`verify_share`] against `commitments` (see
+/// [`SecretSharePackage::commitments`]) before interpolating: a dishonest
+/// dealer or a tampered share is caught here instead of silently skewing the
+/// recovered secret.
+///
+/// `Err(PqcError::ReconstructionFailed)` can mean a reconstructed lane landed
+/// on the one field value (`256`) with no byte representation — see
+/// `field_value_to_byte`. For shares whose secret is itself a sum of
+/// independent contributions (e.g. the aggregate produced by
+/// [`crate::dkg::DistributedKeyGeneration::finalize`]), this is expected and
+/// retryable rather than a bug: a 32-byte, two-participant aggregate hits it
+/// around 1 run in 9. There is no way to recover the same run; the whole
+/// ceremony that produced these shares must restart from scratch.
+pub fn combine_secret(
+    shares: &[Share],
+    commitments: &[Commitment],
+    threshold: u8,
+) -> PqcResult<RecoveredSecret> {
+    if shares.is_empty() {
+        return Err(PqcError::InvalidInput("combine_secret requires at least one share"));
+    }
+    if shares.len() < threshold as usize {
+        return Err(PqcError::InvalidInput("not enough shares to meet the threshold"));
+    }
+    require_single_epoch(shares)?;
+    for share in shares {
+        verify_share(share, commitments, threshold)?;
+    }
+    combine_secret_checked(shares)
+}
+
+/// Reject mixing shares minted by different proactive refreshes
+/// (see [`crate::key_manager::KeyManager::refresh_shares`]): a share that
+/// leaked from a stale epoch must not help reconstruct the current one.
+fn require_single_epoch(shares: &[Share]) -> PqcResult<()> {
+    let epoch = shares[0].metadata.epoch;
+    if shares.iter().any(|share| share.metadata.epoch != epoch) {
+        return Err(PqcError::ShareMismatch("shares span more than one refresh epoch"));
+    }
+    Ok(())
+}
+
+fn combine_secret_checked(shares: &[Share]) -> PqcResult<RecoveredSecret> {
+    let lane_count = decode_lanes(&shares[0].value).len();
+    let points: Vec<(u16, Vec<u16>)> = shares
+        .iter()
+        .map(|share| {
+            let lanes = decode_lanes(&share.value);
+            (share.metadata.share_index as u16, lanes)
+        })
+        .collect();
+
+    for (_, lanes) in &points {
+        if lanes.len() != lane_count {
+            return Err(PqcError::ShareMismatch("shares disagree on secret length"));
+        }
+    }
+
+    let mut secret = Vec::with_capacity(lane_count);
+    for lane in 0..lane_count {
+        let lane_points: Vec<(u16, u16)> = points.iter().map(|(x, v)| (*x, v[lane])).collect();
+        let value = interpolate_at_zero(&lane_points)?;
+        secret.push(field_value_to_byte(value)?);
+    }
+
+    Ok(RecoveredSecret { secret })
+}
+
+/// The result of a [`combine_secret_robust`] decode: the secret, plus every
+/// share index whose byte lanes disagreed with the reconstructed polynomial.
+#[derive(Debug, Clone)]
+pub struct RobustRecovery {
+    pub secret: Bytes,
+    pub corrupted_shares: Vec<u8>,
+}
+
+/// Reconstruct the secret tolerating up to `e` corrupted shares, for any `e`
+/// with `shares.len() >= threshold + 2*e`, via Berlekamp-Welch decoding run
+/// independently on each byte lane.
+///
+/// Unlike [`combine_secret`], this does not require the caller to already
+/// know which shares (if any) are bad; corrupted indices are returned so the
+/// caller (typically [`crate::key_manager::KeyManager`]) can evict them.
+///
+/// Can fail with `Err(PqcError::ReconstructionFailed)` the same expected,
+/// retryable way [`combine_secret`] does when a reconstructed lane lands on
+/// the unrepresentable field value `256` — see its docs.
+pub fn combine_secret_robust(shares: &[Share], threshold: u8) -> PqcResult<RobustRecovery> {
+    let t = threshold as usize;
+    let n = shares.len();
+    if t == 0 || n < t {
+        return Err(PqcError::InvalidInput("not enough shares to meet the threshold"));
+    }
+    require_single_epoch(shares)?;
+
+    let lane_count = decode_lanes(&shares[0].value).len();
+    let mut lane_points: Vec<Vec<(u8, u16, u16)>> = vec![Vec::with_capacity(n); lane_count];
+    for share in shares {
+        let lanes = decode_lanes(&share.value);
+        if lanes.len() != lane_count {
+            return Err(PqcError::ShareMismatch("shares disagree on secret length"));
+        }
+        let x = share.metadata.share_index as u16;
+        for (lane, &y) in lanes.iter().enumerate() {
+            lane_points[lane].push((share.metadata.share_index, x, y));
+        }
+    }
+
+    let mut secret = Vec::with_capacity(lane_count);
+    let mut corrupted_shares = BTreeSet::new();
+
+    for points in &lane_points {
+        let (byte, corrupted) = berlekamp_welch_decode_lane(points, t)?;
+        secret.push(byte);
+        corrupted_shares.extend(corrupted);
+    }
+
+    Ok(RobustRecovery {
+        secret,
+        corrupted_shares: corrupted_shares.into_iter().collect(),
+    })
+}
+
+/// Decode a single byte lane, trying the largest correctable error count
+/// first and falling back to smaller ones until a consistent `(E, N)` pair
+/// is found (or every candidate is exhausted).
+fn berlekamp_welch_decode_lane(
+    points: &[(u8, u16, u16)],
+    t: usize,
+) -> PqcResult<(u8, Vec<u8>)> {
+    let n = points.len();
+    let e_max = n.saturating_sub(t) / 2;
+
+    for e in (0..=e_max).rev() {
+        let unknowns = t + 2 * e;
+        if unknowns == 0 || unknowns > n {
+            continue;
+        }
+
+        // Every one of the `n` available points gets an equation, not just
+        // the first `unknowns` by position: the genuine (E, N) satisfies all
+        // of them (at a corrupted point `y_i` is multiplied by `E(x_i) == 0`,
+        // so the equation holds no matter what the corrupted value is), so
+        // restricting to an arbitrary positional window would miss
+        // corruption that happens to land outside it.
+        let mut matrix = Vec::with_capacity(n);
+        let mut rhs = Vec::with_capacity(n);
+        for &(_, x_i, y_i) in points {
+            let mut row = Vec::with_capacity(unknowns);
+            for k in 0..e {
+                row.push(field_mul(y_i, field_pow(x_i, k as u32)));
+            }
+            for j in 0..(t + e) {
+                row.push(field_sub(0, field_pow(x_i, j as u32)));
+            }
+            matrix.push(row);
+            rhs.push(field_sub(0, field_mul(y_i, field_pow(x_i, e as u32))));
+        }
+
+        let solution = match solve_linear_system(matrix, rhs) {
+            Some(solution) => solution,
+            None => continue,
+        };
+
+        let mut error_locator: Vec<u16> = solution[..e].to_vec();
+        error_locator.push(1); // E is monic
+        let numerator: Vec<u16> = solution[e..].to_vec();
+
+        if points
+            .iter()
+            .any(|&(_, x_i, y_i)| field_mul(y_i, evaluate_polynomial(&error_locator, x_i)) != evaluate_polynomial(&numerator, x_i))
+        {
+            continue;
+        }
+
+        let (quotient, remainder) = match poly_divmod(&numerator, &error_locator) {
+            Some(result) => result,
+            None => continue,
+        };
+        if remainder.iter().any(|&c| c != 0) {
+            continue;
+        }
+
+        let secret_byte = *quotient.first().unwrap_or(&0);
+        let corrupted = points
+            .iter()
+            .filter(|&&(_, x_i, _)| evaluate_polynomial(&error_locator, x_i) == 0)
+            .map(|&(index, _, _)| index)
+            .collect();
+
+        return Ok((field_value_to_byte(secret_byte)?, corrupted));
+    }
+
+    Err(PqcError::ReconstructionFailed(
+        "too many corrupted shares to reconstruct this byte lane",
+    ))
+}
+
+/// Divide `numerator` by `divisor` (both ascending-degree coefficient lists
+/// over `GF(257)`), returning `(quotient, remainder)`.
+fn poly_divmod(numerator: &[u16], divisor: &[u16]) -> Option<(Vec<u16>, Vec<u16>)> {
+    let divisor_degree = poly_degree(divisor)?;
+    let mut remainder = numerator.to_vec();
+    let mut numerator_degree = poly_degree(&remainder);
+
+    if numerator_degree.is_none_or(|d| d < divisor_degree) {
+        return Some((vec![0], remainder));
+    }
+
+    let quotient_degree = numerator_degree.unwrap() - divisor_degree;
+    let mut quotient = vec![0u16; quotient_degree + 1];
+    let divisor_lead_inv = field_inv(divisor[divisor_degree]).ok()?;
+
+    while let Some(cur_degree) = numerator_degree.filter(|&d| d >= divisor_degree) {
+        let lead = remainder[cur_degree];
+        if lead != 0 {
+            let factor = field_mul(lead, divisor_lead_inv);
+            let shift = cur_degree - divisor_degree;
+            quotient[shift] = factor;
+            for (k, &coeff) in divisor.iter().enumerate() {
+                if coeff != 0 {
+                    remainder[shift + k] = field_sub(remainder[shift + k], field_mul(factor, coeff));
+                }
+            }
+        }
+        if cur_degree == 0 {
+            break;
+        }
+        numerator_degree = Some(cur_degree - 1);
+    }
+
+    Some((quotient, remainder))
+}
+
+fn poly_degree(p: &[u16]) -> Option<usize> {
+    p.iter().rposition(|&c| c != 0)
+}
+
+/// Gauss-Jordan elimination over `GF(257)` for a system of `a.len()`
+/// equations in `cols` unknowns, where `a.len()` may exceed `cols` (as it
+/// does whenever [`berlekamp_welch_decode_lane`] has more points available
+/// than the current error hypothesis strictly needs). Returns `None` if the
+/// leading `cols` columns aren't full rank, or if any equation beyond the
+/// `cols` used to pin down a solution turns out inconsistent with it.
+fn solve_linear_system(mut a: Vec<Vec<u16>>, mut b: Vec<u16>) -> Option<Vec<u16>> {
+    let rows = a.len();
+    let cols = a.first()?.len();
+    if rows < cols {
+        return None;
+    }
+
+    let mut pivot_row = 0;
+    for col in 0..cols {
+        let pivot = (pivot_row..rows).find(|&row| a[row][col] != 0)?;
+        a.swap(pivot_row, pivot);
+        b.swap(pivot_row, pivot);
+
+        let inv = field_inv(a[pivot_row][col]).ok()?;
+        for value in a[pivot_row].iter_mut() {
+            *value = field_mul(*value, inv);
+        }
+        b[pivot_row] = field_mul(b[pivot_row], inv);
+
+        let pivot_coeffs = a[pivot_row].clone();
+        let pivot_rhs = b[pivot_row];
+        for (row, (a_row, b_row)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+            if row == pivot_row || a_row[col] == 0 {
+                continue;
+            }
+            let factor = a_row[col];
+            for (value, &pivot_value) in a_row.iter_mut().zip(pivot_coeffs.iter()).skip(col) {
+                *value = field_sub(*value, field_mul(factor, pivot_value));
+            }
+            *b_row = field_sub(*b_row, field_mul(factor, pivot_rhs));
+        }
+
+        pivot_row += 1;
+    }
+
+    // Rows beyond the `cols` pivots are the extra equations from points the
+    // pivot search didn't need; the solution above must satisfy them too, or
+    // this error hypothesis is wrong (too many corrupted points for this `e`).
+    let consistent = a[cols..]
+        .iter()
+        .zip(b[cols..].iter())
+        .all(|(row, &rhs)| row.iter().all(|&v| v == 0) && rhs == 0);
+    if !consistent {
+        return None;
+    }
+
+    Some(b[..cols].to_vec())
+}
+
+/// Proactively re-randomize every share in `package` without changing the
+/// secret it reconstructs to, advancing it to `package.epoch + 1`.
+///
+/// Holders collectively deal a fresh "zero-sharing" — a degree-`t-1`
+/// polynomial whose constant term is zero — and add it lane-wise to their
+/// existing share. Interpolating at `x = 0` still recovers the original
+/// secret, but the share values themselves are now uncorrelated with the
+/// previous epoch's, so shares harvested below the threshold across epochs
+/// can never be combined into a quorum.
+pub fn reshare_zero(
+    package: &SecretSharePackage,
+    created_at: TimestampMs,
+) -> PqcResult<SecretSharePackage> {
+    if package.shares.is_empty() {
+        return Err(PqcError::InvalidInput("package has no shares to refresh"));
+    }
+
+    let lane_count = decode_lanes(&package.shares[0].value).len();
+    let next_epoch = package.epoch + 1;
+    let zero_package = split_secret(
+        &vec![0u8; lane_count],
+        &package.key_id,
+        package.key_version,
+        created_at,
+        next_epoch,
+        package.threshold,
+    )?;
+
+    // Pair each outstanding share with the zero-share minted for the same
+    // `share_index`, not by position: a caller (e.g.
+    // [`crate::key_manager::KeyManager::reconstruct_robust`]) may have
+    // evicted a share from the middle of `package.shares`, which would
+    // otherwise silently pair the wrong holders together and corrupt every
+    // share after the gap.
+    let shares = package
+        .shares
+        .iter()
+        .map(|old| {
+            let zero = zero_package
+                .shares
+                .iter()
+                .find(|zero| zero.metadata.share_index == old.metadata.share_index)
+                .ok_or(PqcError::ShareMismatch(
+                    "outstanding share index has no matching zero-share",
+                ))?;
+            let combined: Vec<u16> = decode_lanes(&old.value)
+                .iter()
+                .zip(decode_lanes(&zero.value).iter())
+                .map(|(&a, &b)| field_add(a, b))
+                .collect();
+            Ok(Share {
+                metadata: ShareMetadata {
+                    created_at,
+                    epoch: next_epoch,
+                    ..old.metadata.clone()
+                },
+                value: encode_lanes(&combined),
+            })
+        })
+        .collect::<PqcResult<Vec<Share>>>()?;
+
+    let commitments = package
+        .commitments
+        .iter()
+        .zip(zero_package.commitments.iter())
+        .map(|(old, zero)| Commitment(mul_mod(old.0, zero.0, COMMITMENT_MODULUS)))
+        .collect();
+
+    Ok(SecretSharePackage {
+        key_id: package.key_id,
+        key_version: package.key_version,
+        created_at,
+        epoch: next_epoch,
+        threshold: package.threshold,
+        shares,
+        commitments,
+    })
+}
+
+fn commit(coefficient: u16) -> Commitment {
+    Commitment(mod_pow(COMMITMENT_GENERATOR, coefficient as u64, COMMITMENT_MODULUS))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn derive_coefficient(
+    domain: &[u8],
+    secret: &[u8],
+    key_id: &KeyId,
+    key_version: u32,
+    created_at: TimestampMs,
+    epoch: u32,
+    lane: usize,
+    coeff_index: usize,
+) -> u16 {
+    let mut digest = Blake2s256::new();
+    digest.update(domain);
+    digest.update(key_id.0);
+    digest.update(key_version.to_le_bytes());
+    digest.update(created_at.to_le_bytes());
+    digest.update(epoch.to_le_bytes());
+    digest.update((lane as u32).to_le_bytes());
+    digest.update((coeff_index as u32).to_le_bytes());
+    digest.update(secret);
+    let out = digest.finalize();
+    let word = u32::from_le_bytes([out[0], out[1], out[2], out[3]]);
+    (word % FIELD_PRIME) as u16
+}
+
+pub(crate) fn encode_lanes(lanes: &[u16]) -> Bytes {
+    let mut out = Vec::with_capacity(lanes.len() * 2);
+    for value in lanes {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+    out
+}
+
+pub(crate) fn decode_lanes(value: &[u8]) -> Vec<u16> {
+    value
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect()
+}
+
+pub(crate) fn field_add(a: u16, b: u16) -> u16 {
+    ((a as u32 + b as u32) % FIELD_PRIME) as u16
+}
+
+/// Narrow a reconstructed `GF(257)` field element back down to the byte it's
+/// supposed to represent. A lane recovered from `split_secret`'s own shares
+/// never hits this, since the dealer's constant term is always `<= 255`, but
+/// a lane reconstructed from summed contributions (e.g. the aggregate secret
+/// out of [`crate::dkg`]) can legitimately land on the one field value, 256,
+/// that has no byte representation — that has to be a reconstruction error,
+/// not a silent `256 as u8 == 0` wraparound.
+fn field_value_to_byte(value: u16) -> PqcResult<u8> {
+    u8::try_from(value)
+        .map_err(|_| PqcError::ReconstructionFailed("reconstructed lane does not fit in a byte"))
+}
+
+fn field_sub(a: u16, b: u16) -> u16 {
+    ((a as u32 + FIELD_PRIME - b as u32) % FIELD_PRIME) as u16
+}
+
+fn field_mul(a: u16, b: u16) -> u16 {
+    ((a as u32 * b as u32) % FIELD_PRIME) as u16
+}
+
+fn field_pow(base: u16, mut exp: u32) -> u16 {
+    let mut result: u32 = 1;
+    let mut base = base as u32 % FIELD_PRIME;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result * base) % FIELD_PRIME;
+        }
+        base = (base * base) % FIELD_PRIME;
+        exp >>= 1;
+    }
+    result as u16
+}
+
+fn field_inv(a: u16) -> PqcResult<u16> {
+    if a == 0 {
+        return Err(PqcError::ReconstructionFailed("division by zero in field"));
+    }
+    // Fermat's little theorem: a^(p-2) == a^-1 mod p for prime p.
+    Ok(field_pow(a, FIELD_PRIME - 2))
+}
+
+/// Evaluate `sum(coeffs[j] * x^j)` over `GF(257)`.
+fn evaluate_polynomial(coeffs: &[u16], x: u16) -> u16 {
+    let mut result = 0u16;
+    for (j, &c) in coeffs.iter().enumerate() {
+        result = field_add(result, field_mul(c, field_pow(x, j as u32)));
+    }
+    result
+}
+
+/// Lagrange-interpolate `points` at `x = 0`.
+fn interpolate_at_zero(points: &[(u16, u16)]) -> PqcResult<u16> {
+    let mut total = 0u16;
+    for (i, &(x_i, y_i)) in points.iter().enumerate() {
+        let mut numerator = 1u16;
+        let mut denominator = 1u16;
+        for (j, &(x_j, _)) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            numerator = field_mul(numerator, x_j);
+            denominator = field_mul(denominator, field_sub(x_j, x_i));
+        }
+        let term = field_mul(y_i, field_mul(numerator, field_inv(denominator)?));
+        total = field_add(total, term);
+    }
+    Ok(total)
+}
+
+pub(crate) fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result: u64 = 1;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_manager::ThresholdPolicy;
+    use crate::types::KeyId;
+
+    fn policy(t: u8, n: u8) -> ThresholdPolicy {
+        ThresholdPolicy { t, n }
+    }
+
+    #[test]
+    fn split_and_combine_round_trips_the_secret() {
+        let secret = b"a pqcnet kem secret key!!".to_vec();
+        let package = split_secret(&secret, &KeyId([7u8; 32]), 1, 1_000, 0, policy(3, 5)).unwrap();
+
+        let recovered = combine_secret(&package.shares[..3], &package.commitments, 3).unwrap();
+        assert_eq!(recovered.secret, secret);
+
+        let recovered = combine_secret(&package.shares[1..4], &package.commitments, 3).unwrap();
+        assert_eq!(recovered.secret, secret);
+    }
+
+    #[test]
+    fn verify_share_rejects_a_tampered_value() {
+        let secret = b"threshold-shared secret".to_vec();
+        let package = split_secret(&secret, &KeyId([1u8; 32]), 1, 1_000, 0, policy(2, 4)).unwrap();
+
+        let mut tampered = package.shares[0].clone();
+        tampered.value[0] ^= 0xFF;
+
+        assert!(matches!(
+            verify_share(&tampered, &package.commitments, 2),
+            Err(PqcError::VerifyFailed)
+        ));
+    }
+
+    #[test]
+    fn combine_secret_rejects_shares_from_different_epochs() {
+        let secret = b"epoch-pinned secret".to_vec();
+        let package = split_secret(&secret, &KeyId([2u8; 32]), 1, 1_000, 0, policy(2, 3)).unwrap();
+        let refreshed = reshare_zero(&package, 2_000).unwrap();
+
+        let mixed = [package.shares[0].clone(), refreshed.shares[1].clone()];
+        assert!(matches!(
+            combine_secret(&mixed, &package.commitments, 2),
+            Err(PqcError::ShareMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn reshare_zero_preserves_the_secret_across_epochs() {
+        let secret = b"proactively refreshed".to_vec();
+        let package = split_secret(&secret, &KeyId([3u8; 32]), 1, 1_000, 0, policy(2, 3)).unwrap();
+        let refreshed = reshare_zero(&package, 2_000).unwrap();
+
+        assert_eq!(refreshed.epoch, 1);
+        let recovered = combine_secret(&refreshed.shares[..2], &refreshed.commitments, 2).unwrap();
+        assert_eq!(recovered.secret, secret);
+    }
+
+    #[test]
+    fn reshare_zero_pairs_by_share_index_after_a_share_is_evicted() {
+        let secret = b"survives eviction".to_vec();
+        let mut package = split_secret(&secret, &KeyId([9u8; 32]), 1, 1_000, 0, policy(2, 4)).unwrap();
+        // Mimic KeyManager::reconstruct_robust evicting a share from the
+        // middle of the list, leaving indices [1, 2, 4] at positions [0, 1, 2].
+        package.shares.retain(|share| share.metadata.share_index != 3);
+
+        let refreshed = reshare_zero(&package, 2_000).unwrap();
+        let recovered = combine_secret(&refreshed.shares[..2], &refreshed.commitments, 2).unwrap();
+        assert_eq!(recovered.secret, secret);
+    }
+
+    #[test]
+    fn combine_secret_rejects_too_few_shares() {
+        let secret = b"a pqcnet kem secret key!!".to_vec();
+        let package = split_secret(&secret, &KeyId([8u8; 32]), 1, 1_000, 0, policy(3, 5)).unwrap();
+
+        assert!(matches!(
+            combine_secret(&package.shares[..1], &package.commitments, 3),
+            Err(PqcError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn combine_secret_robust_tolerates_and_reports_a_corrupted_share() {
+        let secret = b"byzantine-tolerant".to_vec();
+        // t + 2e <= n, so with t=2 a single corrupted share (e=1) needs n>=4.
+        let package = split_secret(&secret, &KeyId([4u8; 32]), 1, 1_000, 0, policy(2, 4)).unwrap();
+
+        let mut shares = package.shares.clone();
+        shares[3].value[0] ^= 0x01;
+
+        let recovery = combine_secret_robust(&shares, 2).unwrap();
+        assert_eq!(recovery.secret, secret);
+        assert_eq!(recovery.corrupted_shares, vec![4]);
+    }
+
+    #[test]
+    fn combine_secret_robust_tolerates_corruption_outside_the_minimal_quorum() {
+        let secret = b"slack-tolerant".to_vec();
+        // n (6) exceeds the minimal t + 2e (2 + 2*1 = 4) needed for e=1, so
+        // the decoder must use every point, not just the first `t + 2e` by
+        // position, to catch corruption that lands in the slack.
+        let package = split_secret(&secret, &KeyId([5u8; 32]), 1, 1_000, 0, policy(2, 6)).unwrap();
+
+        let mut shares = package.shares.clone();
+        shares[5].value[0] ^= 0x01;
+
+        let recovery = combine_secret_robust(&shares, 2).unwrap();
+        assert_eq!(recovery.secret, secret);
+        assert_eq!(recovery.corrupted_shares, vec![6]);
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared value types used across the PQC engines and key-management layer.
+
+use alloc::vec::Vec;
+
+/// A heap-allocated byte buffer; the common currency for keys, ciphertexts
+/// and shares throughout the crate.
+pub type Bytes = Vec<u8>;
+
+/// Milliseconds since the Unix epoch, as supplied by the host environment.
+pub type TimestampMs = u64;
+
+/// Security level advertised by a PQC engine adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityLevel {
+    MlKem128,
+    MlKem192,
+    MlKem256,
+    MlDsa128,
+    MlDsa192,
+    MlDsa256,
+}
+
+/// Opaque identifier for a managed key, independent of its current version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyId(pub [u8; 32]);